@@ -0,0 +1,18 @@
+//! Recursively find Rust source files under a root path.
+
+use std::path::{Path, PathBuf};
+
+use walkdir::WalkDir;
+
+/// Collect every `.rs` file under `root`, skipping `target/` build
+/// directories.
+pub fn find_rust_files(root: &Path) -> Vec<PathBuf> {
+    WalkDir::new(root)
+        .into_iter()
+        .filter_entry(|e| e.file_name() != "target")
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file())
+        .map(|e| e.into_path())
+        .filter(|p| p.extension().map(|ext| ext == "rs").unwrap_or(false))
+        .collect()
+}
@@ -0,0 +1,4 @@
+//! Language frontends: turn raw source text into the [`crate::model`]
+//! construct types that the rest of CodeLyzer operates on.
+
+pub mod rust_syn;
@@ -0,0 +1,246 @@
+//! `syn`-based Rust frontend.
+//!
+//! Parses each file once into a full syntax tree and derives every
+//! construct from it, rather than re-scanning the source text with a
+//! different regex per construct kind. This gets trait-impl associations right
+//! even across multi-line generic bounds, and doesn't miss or mis-match
+//! on nested generics like `HashMap<Role, Vec<User>>` the way brace- and
+//! regex-matching over raw text could.
+
+use std::path::Path;
+
+use syn::spanned::Spanned;
+use syn::{Generics, ImplItem, Item, ItemEnum, ItemFn, ItemImpl, Signature};
+
+use crate::model::{EnumDef, Function, ImplBlock, SourceFile};
+use crate::text_scan::line_of;
+use crate::trace::Tracer;
+
+/// Parse a single file's source text into its constructs.
+pub fn parse_file(path: &Path, source: &str) -> SourceFile {
+    parse_file_traced(path, source, &Tracer::disabled())
+}
+
+/// Parse a single file's source text into its constructs, opening a
+/// child span under the active span for each `impl` block, plus one for
+/// all free functions and one for all enums (see [`crate::pipeline`]).
+pub fn parse_file_traced(path: &Path, source: &str, tracer: &Tracer) -> SourceFile {
+    let Ok(ast) = syn::parse_file(source) else {
+        return SourceFile {
+            path: path.to_path_buf(),
+            source: source.to_string(),
+            functions: Vec::new(),
+            enums: Vec::new(),
+            impls: Vec::new(),
+        };
+    };
+
+    let mut items = Vec::new();
+    flatten_items(&ast.items, &mut items);
+
+    let impls = {
+        let mut impls = Vec::new();
+        for item in &items {
+            if let Item::Impl(item_impl) = item {
+                let label = format!("impl {}", self_type_name(item_impl));
+                let _span = tracer.span(&label);
+                let block = impl_from_item(source, item_impl);
+                tracer.record_items(block.methods.len());
+                impls.push(block);
+            }
+        }
+        impls
+    };
+
+    let functions = {
+        let _span = tracer.span("functions");
+        let functions: Vec<Function> = items
+            .iter()
+            .filter_map(|item| match item {
+                Item::Fn(item_fn) => Some(function_from_item(source, item_fn)),
+                _ => None,
+            })
+            .collect();
+        tracer.record_items(functions.len());
+        functions
+    };
+
+    let enums = {
+        let _span = tracer.span("enums");
+        let enums: Vec<EnumDef> = items
+            .iter()
+            .filter_map(|item| match item {
+                Item::Enum(item_enum) => Some(enum_from_item(source, item_enum)),
+                _ => None,
+            })
+            .collect();
+        tracer.record_items(enums.len());
+        enums
+    };
+
+    SourceFile {
+        path: path.to_path_buf(),
+        source: source.to_string(),
+        functions,
+        enums,
+        impls,
+    }
+}
+
+/// Collect every item in the file, descending into inline `mod` blocks
+/// (e.g. `mod tests { .. }`) so constructs nested inside them are found
+/// too; items inside an out-of-line `mod foo;` live in another file and
+/// aren't visited here.
+fn flatten_items<'a>(items: &'a [Item], out: &mut Vec<&'a Item>) {
+    for item in items {
+        if let Item::Mod(item_mod) = item {
+            if let Some((_, inline_items)) = &item_mod.content {
+                flatten_items(inline_items, out);
+            }
+        }
+        out.push(item);
+    }
+}
+
+fn function_from_item(source: &str, item_fn: &ItemFn) -> Function {
+    function_from_sig(source, &item_fn.sig, item_fn.block.span())
+}
+
+fn function_from_sig(source: &str, sig: &Signature, block_span: proc_macro2::Span) -> Function {
+    let range = block_span.byte_range();
+    Function {
+        name: sig.ident.to_string(),
+        is_async: sig.asyncness.is_some(),
+        generics: generic_param_names(&sig.generics),
+        start_line: line_of(source, sig.fn_token.span().byte_range().start),
+        end_line: line_of(source, range.end.saturating_sub(1)),
+        body: source[range.start + 1..range.end - 1].to_string(),
+    }
+}
+
+fn impl_from_item(source: &str, item_impl: &ItemImpl) -> ImplBlock {
+    let methods = item_impl
+        .items
+        .iter()
+        .filter_map(|item| match item {
+            ImplItem::Fn(method) => Some(function_from_sig(source, &method.sig, method.block.span())),
+            _ => None,
+        })
+        .collect();
+
+    ImplBlock {
+        trait_name: item_impl
+            .trait_
+            .as_ref()
+            .and_then(|(_, path, _)| path.segments.last())
+            .map(|seg| seg.ident.to_string()),
+        type_name: self_type_name(item_impl),
+        generics: generic_param_names(&item_impl.generics),
+        methods,
+        start_line: line_of(source, item_impl.span().byte_range().start),
+    }
+}
+
+fn enum_from_item(source: &str, item_enum: &ItemEnum) -> EnumDef {
+    EnumDef {
+        name: item_enum.ident.to_string(),
+        variants: item_enum.variants.iter().map(|v| v.ident.to_string()).collect(),
+        start_line: line_of(source, item_enum.span().byte_range().start),
+    }
+}
+
+/// The name of the type an `impl` block is for, e.g. `UserRepository` in
+/// `impl Repository<User> for UserRepository`.
+fn self_type_name(item_impl: &ItemImpl) -> String {
+    match item_impl.self_ty.as_ref() {
+        syn::Type::Path(type_path) => type_path
+            .path
+            .segments
+            .last()
+            .map(|seg| seg.ident.to_string())
+            .unwrap_or_else(|| "<unknown>".to_string()),
+        _ => "<unknown>".to_string(),
+    }
+}
+
+fn generic_param_names(generics: &Generics) -> Vec<String> {
+    generics
+        .params
+        .iter()
+        .filter_map(|p| match p {
+            syn::GenericParam::Type(t) => Some(t.ident.to_string()),
+            _ => None,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn parse(src: &str) -> SourceFile {
+        parse_file(&PathBuf::from("test.rs"), src)
+    }
+
+    #[test]
+    fn extracts_top_level_function() {
+        let file = parse("fn add(a: i32, b: i32) -> i32 {\n    a + b\n}\n");
+        assert_eq!(file.functions.len(), 1);
+        assert_eq!(file.functions[0].name, "add");
+        assert!(!file.functions[0].is_async);
+    }
+
+    #[test]
+    fn extracts_async_function() {
+        let file = parse("async fn fetch() -> u32 {\n    1\n}\n");
+        assert!(file.functions[0].is_async);
+    }
+
+    #[test]
+    fn extracts_impl_methods_separately_from_free_functions() {
+        let src = "struct User;\nimpl User {\n    fn is_admin(&self) -> bool {\n        true\n    }\n}\n";
+        let file = parse(src);
+        assert!(file.functions.is_empty());
+        assert_eq!(file.impls.len(), 1);
+        assert_eq!(file.impls[0].type_name, "User");
+        assert_eq!(file.impls[0].methods[0].name, "is_admin");
+    }
+
+    #[test]
+    fn links_trait_impl_to_trait_name_across_nested_generics() {
+        let src = "impl Repository<User> for UserRepository {\n    fn find_all(&self) -> HashMap<Role, Vec<User>> { HashMap::new() }\n}\n";
+        let file = parse(src);
+        assert_eq!(file.impls[0].trait_name.as_deref(), Some("Repository"));
+        assert_eq!(file.impls[0].type_name, "UserRepository");
+    }
+
+    #[test]
+    fn extracts_enum_variants() {
+        let src = "enum Role {\n    Admin,\n    Editor,\n    Viewer,\n}\n";
+        let file = parse(src);
+        assert_eq!(file.enums[0].name, "Role");
+        assert_eq!(file.enums[0].variants, vec!["Admin", "Editor", "Viewer"]);
+    }
+
+    #[test]
+    fn extracts_impl_generic_params() {
+        let src = "impl<T: Clone> Cache<T> {\n    fn get(&self) -> T { self.0.clone() }\n}\n";
+        let file = parse(src);
+        assert_eq!(file.impls[0].generics, vec!["T"]);
+    }
+
+    #[test]
+    fn extracts_function_generic_params_across_multiline_where_clause() {
+        let src = "fn transform_users<F>(users: &[User], transformer: F) -> Vec<String>\nwhere\n    F: Fn(&User) -> String,\n{\n    users.iter().map(transformer).collect()\n}\n";
+        let file = parse(src);
+        assert_eq!(file.functions[0].generics, vec!["F"]);
+    }
+
+    #[test]
+    fn falls_back_to_empty_source_file_on_parse_error() {
+        let file = parse("fn broken( {");
+        assert!(file.functions.is_empty());
+        assert!(file.impls.is_empty());
+    }
+}
@@ -0,0 +1,10 @@
+//! CodeLyzer: static analysis and metrics for Rust codebases.
+
+pub mod discovery;
+pub mod frontend;
+pub mod metrics;
+pub mod model;
+pub mod pipeline;
+pub mod report;
+pub mod text_scan;
+pub mod trace;
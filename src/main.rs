@@ -0,0 +1,131 @@
+//! CodeLyzer CLI entry point.
+
+use std::path::{Path, PathBuf};
+
+use clap::{Parser, Subcommand};
+
+use codelyzer::discovery::find_rust_files;
+use codelyzer::frontend::rust_syn::parse_file;
+use codelyzer::metrics::{complexity, concurrency, rbac};
+use codelyzer::pipeline;
+use codelyzer::report;
+use codelyzer::trace::{self, Tracer};
+
+#[derive(Parser)]
+#[command(name = "codelyzer", about = "Static analysis and metrics for Rust codebases")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Report cognitive complexity per function and per file.
+    Complexity {
+        /// Directory or file to analyze.
+        path: PathBuf,
+        /// Flag functions scoring above this threshold.
+        #[arg(long, default_value_t = 15)]
+        threshold: u32,
+    },
+    /// Report concurrency patterns: shared state, spawn fan-out, warnings.
+    Concurrency {
+        /// Directory or file to analyze.
+        path: PathBuf,
+    },
+    /// Report role-based access control patterns: role enums, predicate
+    /// methods, and dispatch coverage.
+    Rbac {
+        /// Directory or file to analyze.
+        path: PathBuf,
+    },
+    /// Run the full pipeline (discovery, parsing, metrics) and print its
+    /// reports, optionally profiling the run as a span tree.
+    Analyze {
+        /// Directory or file to analyze.
+        path: PathBuf,
+        /// Print a hierarchical trace of time spent per pipeline phase.
+        #[arg(long)]
+        trace: bool,
+        /// Also run the RBAC rule pack.
+        #[arg(long)]
+        rbac: bool,
+    },
+}
+
+fn main() -> std::io::Result<()> {
+    let cli = Cli::parse();
+    match cli.command {
+        Command::Complexity { path, threshold } => run_complexity(&path, threshold),
+        Command::Concurrency { path } => run_concurrency(&path),
+        Command::Rbac { path } => run_rbac(&path),
+        Command::Analyze { path, trace, rbac } => run_analyze(&path, trace, rbac),
+    }
+}
+
+fn run_complexity(path: &Path, threshold: u32) -> std::io::Result<()> {
+    let files = if path.is_dir() {
+        find_rust_files(path)
+    } else {
+        vec![path.to_path_buf()]
+    };
+
+    for file_path in files {
+        let source = std::fs::read_to_string(&file_path)?;
+        let source_file = parse_file(&file_path, &source);
+        let complexity_report = complexity::analyze_file(&source_file);
+        report::print_complexity_report(&file_path, &complexity_report, threshold);
+    }
+    Ok(())
+}
+
+fn run_concurrency(path: &Path) -> std::io::Result<()> {
+    let files = if path.is_dir() {
+        find_rust_files(path)
+    } else {
+        vec![path.to_path_buf()]
+    };
+
+    for file_path in files {
+        let source = std::fs::read_to_string(&file_path)?;
+        let source_file = parse_file(&file_path, &source);
+        let profile = concurrency::analyze_file(&source_file);
+        report::print_concurrency_report(&file_path, &profile);
+    }
+    Ok(())
+}
+
+fn run_rbac(path: &Path) -> std::io::Result<()> {
+    let files = if path.is_dir() {
+        find_rust_files(path)
+    } else {
+        vec![path.to_path_buf()]
+    };
+
+    for file_path in files {
+        let source = std::fs::read_to_string(&file_path)?;
+        let source_file = parse_file(&file_path, &source);
+        let rbac_report = rbac::analyze_file(&source_file);
+        report::print_rbac_report(&file_path, &rbac_report);
+    }
+    Ok(())
+}
+
+fn run_analyze(path: &Path, trace: bool, include_rbac: bool) -> std::io::Result<()> {
+    let tracer = Tracer::new(trace);
+    let reports = pipeline::run(path, &tracer, include_rbac)?;
+
+    for file_report in &reports {
+        report::print_complexity_report(&file_report.path, &file_report.complexity, 15);
+        report::print_concurrency_report(&file_report.path, &file_report.concurrency);
+        if let Some(rbac_report) = &file_report.rbac {
+            report::print_rbac_report(&file_report.path, rbac_report);
+        }
+    }
+
+    if trace {
+        println!("--- trace ---");
+        print!("{}", trace::render(&tracer.into_roots()));
+    }
+    Ok(())
+}
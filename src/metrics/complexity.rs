@@ -0,0 +1,220 @@
+//! Cognitive complexity scoring for functions.
+//!
+//! Roughly follows the "cognitive complexity" idea popularized by
+//! SonarSource: every branching construct (`if`, `match`, `for`, `while`,
+//! `loop`) adds one point, plus one extra point per level of nesting it
+//! sits at, so the same construct costs more the deeper it's buried.
+//! `?`-propagation sites and extra operators in a `&&`/`||` chain add
+//! flat points without a nesting penalty, since they don't add a new
+//! level of indentation for the reader to track.
+//!
+//! Function bodies come from the frontend's AST; nesting depth itself is
+//! still derived by matching braces over the body text rather than
+//! walking it as a tree, since a flat containment check is all this
+//! metric needs.
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::model::{Function, SourceFile};
+use crate::text_scan::{find_block_start, find_matching_brace};
+
+static CONSTRUCT_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\b(if|for|while|loop|match)\b").unwrap());
+static BOOL_OP_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"&&|\|\|").unwrap());
+static TRY_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"[\w\)\]]\?(?:\s|;|,|\)|$)").unwrap());
+
+/// Cognitive complexity for a single function.
+#[derive(Debug, Clone)]
+pub struct FunctionComplexity {
+    pub name: String,
+    pub start_line: usize,
+    pub score: u32,
+    pub structural_points: u32,
+    pub nesting_points: u32,
+    pub boolean_chain_points: u32,
+    pub try_points: u32,
+}
+
+/// Aggregate complexity for a whole file.
+#[derive(Debug, Clone)]
+pub struct FileComplexity {
+    pub total_score: u32,
+    pub functions: Vec<FunctionComplexity>,
+}
+
+/// Score every function (including methods) in `file`.
+pub fn analyze_file(file: &SourceFile) -> FileComplexity {
+    let functions: Vec<FunctionComplexity> = file
+        .all_functions()
+        .into_iter()
+        .map(score_function)
+        .collect();
+    FileComplexity {
+        total_score: functions.iter().map(|f| f.score).sum(),
+        functions,
+    }
+}
+
+/// Functions in `report` whose score exceeds `threshold`, highest first.
+pub fn over_threshold(report: &FileComplexity, threshold: u32) -> Vec<&FunctionComplexity> {
+    let mut flagged: Vec<&FunctionComplexity> =
+        report.functions.iter().filter(|f| f.score > threshold).collect();
+    flagged.sort_by_key(|f| std::cmp::Reverse(f.score));
+    flagged
+}
+
+/// A branching construct's span within a function body, used to derive
+/// nesting depth by containment rather than a real syntax tree.
+struct ConstructSpan {
+    start: usize,
+    end: usize,
+}
+
+fn construct_spans(body: &str) -> Vec<ConstructSpan> {
+    CONSTRUCT_RE
+        .find_iter(body)
+        .filter_map(|m| {
+            let block_start = find_block_start(body, m.end())?;
+            let block_end = find_matching_brace(body, block_start)?;
+            Some(ConstructSpan {
+                start: m.start(),
+                end: block_end,
+            })
+        })
+        .collect()
+}
+
+fn nesting_depth(spans: &[ConstructSpan], index: usize) -> u32 {
+    let target = &spans[index];
+    spans
+        .iter()
+        .enumerate()
+        .filter(|(i, s)| *i != index && s.start < target.start && target.start < s.end)
+        .count() as u32
+}
+
+fn boolean_chain_points(body: &str) -> u32 {
+    body.split([';', '{', '}'])
+        .map(|chunk| {
+            let hits = BOOL_OP_RE.find_iter(chunk).count() as u32;
+            hits.saturating_sub(1)
+        })
+        .sum()
+}
+
+fn score_function(function: &Function) -> FunctionComplexity {
+    let spans = construct_spans(&function.body);
+    let mut structural_points = 0u32;
+    let mut nesting_points = 0u32;
+    for i in 0..spans.len() {
+        structural_points += 1;
+        nesting_points += nesting_depth(&spans, i);
+    }
+    let boolean_chain_points = boolean_chain_points(&function.body);
+    let try_points = TRY_RE.find_iter(&function.body).count() as u32;
+
+    FunctionComplexity {
+        name: function.name.clone(),
+        start_line: function.start_line,
+        score: structural_points + nesting_points + boolean_chain_points + try_points,
+        structural_points,
+        nesting_points,
+        boolean_chain_points,
+        try_points,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::frontend::rust_syn::parse_file;
+    use std::path::PathBuf;
+
+    fn complexity_of(src: &str, name: &str) -> FunctionComplexity {
+        let file = parse_file(&PathBuf::from("t.rs"), src);
+        analyze_file(&file)
+            .functions
+            .into_iter()
+            .find(|f| f.name == name)
+            .unwrap()
+    }
+
+    #[test]
+    fn flat_match_scores_low() {
+        let src = r#"
+            fn describe_role(role: &Role) -> String {
+                match role {
+                    Role::Admin => "a".to_string(),
+                    Role::Editor => "e".to_string(),
+                    Role::Viewer => "v".to_string(),
+                }
+            }
+        "#;
+        let c = complexity_of(src, "describe_role");
+        assert_eq!(c.score, 1);
+    }
+
+    #[test]
+    fn nested_match_in_loop_scores_higher_than_flat_match() {
+        let nested = r#"
+            fn process(users: &[User]) {
+                for user in users {
+                    match user.role {
+                        Role::Admin => {
+                            if let Some(x) = opt {
+                                x.push(user);
+                            } else {
+                                y.push(user);
+                            }
+                        }
+                        Role::Editor => {}
+                        Role::Viewer => {}
+                    }
+                }
+            }
+        "#;
+        let flat = r#"
+            fn describe_role(role: &Role) -> String {
+                match role {
+                    Role::Admin => "a".to_string(),
+                    Role::Editor => "e".to_string(),
+                    Role::Viewer => "v".to_string(),
+                }
+            }
+        "#;
+        let nested_score = complexity_of(nested, "process").score;
+        let flat_score = complexity_of(flat, "describe_role").score;
+        assert!(
+            nested_score > flat_score,
+            "expected nested ({nested_score}) > flat ({flat_score})"
+        );
+    }
+
+    #[test]
+    fn boolean_chain_adds_points_after_first_operator() {
+        let src = r#"
+            fn check(a: bool, b: bool, c: bool) -> bool {
+                if a && b && c {
+                    true
+                } else {
+                    false
+                }
+            }
+        "#;
+        let c = complexity_of(src, "check");
+        assert_eq!(c.boolean_chain_points, 1);
+    }
+
+    #[test]
+    fn try_operator_adds_flat_point() {
+        let src = r#"
+            fn load() -> Result<()> {
+                do_thing()?;
+                Ok(())
+            }
+        "#;
+        let c = complexity_of(src, "load");
+        assert_eq!(c.try_points, 1);
+    }
+}
@@ -0,0 +1,201 @@
+//! Detection of concurrency patterns: shared-state handles, thread/task
+//! fan-out, and lint-style warnings for shapes that are easy to get
+//! wrong (locking inside a loop, unwrapping a poisoned lock or a panicked
+//! thread's join result).
+//!
+//! Like [`crate::metrics::complexity`], this uses regexes plus brace
+//! matching rather than walking the frontend's AST, since these patterns
+//! are easier to spot as text shapes than as syntax tree shapes. Unlike
+//! it, this scans the whole file's raw source text, not function bodies.
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::model::{LineSpan, SourceFile};
+use crate::text_scan::{find_block_start, find_matching_brace, line_of};
+
+static ARC_MUTEX_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"Arc\s*(?:::new\s*\(\s*|<\s*)Mutex\s*(?:::new\s*\(|<)").unwrap());
+static ARC_RWLOCK_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"Arc\s*(?:::new\s*\(\s*|<\s*)RwLock\s*(?:::new\s*\(|<)").unwrap());
+static THREAD_SPAWN_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"thread::spawn").unwrap());
+static JOIN_ALL_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"join_all").unwrap());
+static LOCK_CALL_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"\.lock\(\)").unwrap());
+static LOCK_UNWRAP_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"\.lock\(\)\.unwrap\(\)").unwrap());
+static JOIN_UNWRAP_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"\.join\(\)\.unwrap\(\)").unwrap());
+static LOOP_KEYWORD_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"\b(for|while|loop)\b").unwrap());
+
+/// A handle to state shared across threads/tasks, e.g. `Arc<Mutex<T>>`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SharedStateKind {
+    ArcMutex,
+    ArcRwLock,
+}
+
+#[derive(Debug, Clone)]
+pub struct SharedStateHandle {
+    pub kind: SharedStateKind,
+    pub line: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WarningKind {
+    /// A lock is acquired inside the body of a loop, risking contention
+    /// or a long hold time across iterations.
+    LockInLoop,
+    /// `.lock().unwrap()` panics if the mutex was poisoned by another
+    /// thread panicking while holding it.
+    LockUnwrap,
+    /// `.join().unwrap()` panics if the spawned thread itself panicked.
+    JoinUnwrap,
+}
+
+#[derive(Debug, Clone)]
+pub struct ConcurrencyWarning {
+    pub kind: WarningKind,
+    pub span: LineSpan,
+    pub message: String,
+}
+
+/// Concurrency profile for a single file.
+#[derive(Debug, Clone, Default)]
+pub struct ConcurrencyProfile {
+    pub shared_state: Vec<SharedStateHandle>,
+    pub thread_spawns: usize,
+    pub join_all_batches: usize,
+    pub warnings: Vec<ConcurrencyWarning>,
+}
+
+/// Analyze a file's source text for concurrency patterns.
+pub fn analyze_file(file: &SourceFile) -> ConcurrencyProfile {
+    let source = &file.source;
+    let loop_spans = loop_spans(source);
+
+    let mut shared_state: Vec<SharedStateHandle> = ARC_MUTEX_RE
+        .find_iter(source)
+        .map(|m| SharedStateHandle {
+            kind: SharedStateKind::ArcMutex,
+            line: line_of(source, m.start()),
+        })
+        .chain(ARC_RWLOCK_RE.find_iter(source).map(|m| SharedStateHandle {
+            kind: SharedStateKind::ArcRwLock,
+            line: line_of(source, m.start()),
+        }))
+        .collect();
+    shared_state.sort_by_key(|h| h.line);
+
+    let mut warnings = Vec::new();
+    for lock_call in LOCK_CALL_RE.find_iter(source) {
+        if let Some(enclosing) = loop_spans
+            .iter()
+            .find(|s| s.start < lock_call.start() && lock_call.start() < s.end)
+        {
+            warnings.push(ConcurrencyWarning {
+                kind: WarningKind::LockInLoop,
+                span: LineSpan {
+                    start: line_of(source, enclosing.start),
+                    end: line_of(source, enclosing.end),
+                },
+                message: "lock acquired inside a loop body; each iteration re-contends for the lock".to_string(),
+            });
+        }
+    }
+    for m in LOCK_UNWRAP_RE.find_iter(source) {
+        let line = line_of(source, m.start());
+        warnings.push(ConcurrencyWarning {
+            kind: WarningKind::LockUnwrap,
+            span: LineSpan { start: line, end: line },
+            message: "unwrapping a lock panics if the mutex was poisoned".to_string(),
+        });
+    }
+    for m in JOIN_UNWRAP_RE.find_iter(source) {
+        let line = line_of(source, m.start());
+        warnings.push(ConcurrencyWarning {
+            kind: WarningKind::JoinUnwrap,
+            span: LineSpan { start: line, end: line },
+            message: "unwrapping a join panics if the spawned thread panicked".to_string(),
+        });
+    }
+    warnings.sort_by_key(|w| w.span.start);
+
+    ConcurrencyProfile {
+        shared_state,
+        thread_spawns: THREAD_SPAWN_RE.find_iter(source).count(),
+        join_all_batches: JOIN_ALL_RE.find_iter(source).count(),
+        warnings,
+    }
+}
+
+/// Spans of every `for`/`while`/`loop` block in `source`, used to check
+/// whether some later offset falls inside a loop body.
+fn loop_spans(source: &str) -> Vec<LineSpanOffset> {
+    LOOP_KEYWORD_RE
+        .find_iter(source)
+        .filter_map(|m| {
+            let block_start = find_block_start(source, m.end())?;
+            let block_end = find_matching_brace(source, block_start)?;
+            Some(LineSpanOffset { start: m.start(), end: block_end })
+        })
+        .collect()
+}
+
+struct LineSpanOffset {
+    start: usize,
+    end: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::frontend::rust_syn::parse_file;
+    use std::path::PathBuf;
+
+    fn profile_of(src: &str) -> ConcurrencyProfile {
+        let file = parse_file(&PathBuf::from("t.rs"), src);
+        analyze_file(&file)
+    }
+
+    #[test]
+    fn detects_arc_mutex_constructed_via_new() {
+        let profile = profile_of("let c = Arc::new(Mutex::new(0));\n");
+        assert_eq!(profile.shared_state.len(), 1);
+        assert_eq!(profile.shared_state[0].kind, SharedStateKind::ArcMutex);
+    }
+
+    #[test]
+    fn detects_arc_mutex_from_type_annotation() {
+        let profile = profile_of("let x: Arc<Mutex<i32>> = c;\n");
+        assert_eq!(profile.shared_state.len(), 1);
+        assert_eq!(profile.shared_state[0].kind, SharedStateKind::ArcMutex);
+    }
+
+    #[test]
+    fn counts_thread_spawns_and_join_all_batches() {
+        let src = "fn f() {\n    thread::spawn(|| {});\n    thread::spawn(|| {});\n    futures::future::join_all(tasks);\n}\n";
+        let profile = profile_of(src);
+        assert_eq!(profile.thread_spawns, 2);
+        assert_eq!(profile.join_all_batches, 1);
+    }
+
+    #[test]
+    fn flags_lock_acquired_inside_loop() {
+        let src = "fn f(counter: Arc<Mutex<i32>>) {\n    for _ in 0..10 {\n        let mut n = counter.lock().unwrap();\n        *n += 1;\n    }\n}\n";
+        let profile = profile_of(src);
+        assert!(profile.warnings.iter().any(|w| w.kind == WarningKind::LockInLoop));
+        assert!(profile.warnings.iter().any(|w| w.kind == WarningKind::LockUnwrap));
+    }
+
+    #[test]
+    fn flags_join_unwrap() {
+        let src = "fn f(h: JoinHandle<()>) {\n    h.join().unwrap();\n}\n";
+        let profile = profile_of(src);
+        assert!(profile.warnings.iter().any(|w| w.kind == WarningKind::JoinUnwrap));
+    }
+
+    #[test]
+    fn no_warning_for_lock_outside_loop() {
+        let src = "fn f(counter: Arc<Mutex<i32>>) {\n    let mut n = counter.lock().unwrap();\n    *n += 1;\n}\n";
+        let profile = profile_of(src);
+        assert!(!profile.warnings.iter().any(|w| w.kind == WarningKind::LockInLoop));
+    }
+}
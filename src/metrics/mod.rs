@@ -0,0 +1,5 @@
+//! Metrics computed from parsed [`crate::model::SourceFile`]s.
+
+pub mod complexity;
+pub mod concurrency;
+pub mod rbac;
@@ -0,0 +1,244 @@
+//! Authorization/RBAC pattern report.
+//!
+//! An optional rule pack for codebases that encode access control as a
+//! role enum plus `match`/`if` dispatch on it, rather than through a
+//! policy-model library. It doesn't understand *what* each role is
+//! allowed to do — only where a role is defined, where it's checked, and
+//! where that checking looks incomplete — so a security reviewer knows
+//! where to go read the actual logic.
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::model::SourceFile;
+use crate::text_scan::{find_block_start, find_matching_brace};
+
+static ROLE_ENUM_NAME_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?i)role").unwrap());
+static PREDICATE_METHOD_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"^is_\w+$").unwrap());
+static MATCH_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"\bmatch\b").unwrap());
+static WILDCARD_ARM_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"_\s*=>").unwrap());
+
+/// A method whose name suggests it gates behavior on a role, e.g.
+/// `is_admin`.
+#[derive(Debug, Clone)]
+pub struct PredicateMethod {
+    pub name: String,
+    pub start_line: usize,
+}
+
+/// A `match` expression that dispatches on a role enum but only handles
+/// some of its variants explicitly, letting the rest fall through a
+/// wildcard arm (or simply not appearing at all within the function).
+#[derive(Debug, Clone)]
+pub struct PartialCoverageSite {
+    pub function: String,
+    pub covered_variants: Vec<String>,
+    pub has_wildcard: bool,
+}
+
+/// What CodeLyzer found about one role enum: its variants, which
+/// functions reference them, which variants are never referenced
+/// anywhere, and any match sites that only cover some variants.
+#[derive(Debug, Clone)]
+pub struct RoleReport {
+    pub enum_name: String,
+    pub variants: Vec<String>,
+    pub referencing_functions: Vec<String>,
+    pub unchecked_variants: Vec<String>,
+    pub partial_coverage_sites: Vec<PartialCoverageSite>,
+}
+
+/// RBAC report for a single file.
+#[derive(Debug, Clone, Default)]
+pub struct RbacReport {
+    pub roles: Vec<RoleReport>,
+    pub predicate_methods: Vec<PredicateMethod>,
+}
+
+/// Analyze a parsed file for role enums, predicate methods, and role
+/// dispatch coverage.
+pub fn analyze_file(file: &SourceFile) -> RbacReport {
+    let role_enums: Vec<_> = file
+        .enums
+        .iter()
+        .filter(|e| ROLE_ENUM_NAME_RE.is_match(&e.name))
+        .collect();
+
+    let predicate_methods = file
+        .all_functions()
+        .into_iter()
+        .filter(|f| PREDICATE_METHOD_RE.is_match(&f.name))
+        .map(|f| PredicateMethod {
+            name: f.name.clone(),
+            start_line: f.start_line,
+        })
+        .collect();
+
+    let roles = role_enums
+        .into_iter()
+        .map(|role_enum| analyze_role(role_enum, file))
+        .collect();
+
+    RbacReport {
+        roles,
+        predicate_methods,
+    }
+}
+
+fn analyze_role(role_enum: &crate::model::EnumDef, file: &SourceFile) -> RoleReport {
+    let variant_res: Vec<Regex> = role_enum
+        .variants
+        .iter()
+        .map(|v| Regex::new(&format!(r"\b{}::{}\b", regex::escape(&role_enum.name), regex::escape(v))).unwrap())
+        .collect();
+
+    let mut referencing_functions = Vec::new();
+    let mut referenced_variants = std::collections::HashSet::new();
+    let mut partial_coverage_sites = Vec::new();
+
+    for function in file.all_functions() {
+        let mut hit_any = false;
+        for (variant, re) in role_enum.variants.iter().zip(&variant_res) {
+            if re.is_match(&function.body) {
+                hit_any = true;
+                referenced_variants.insert(variant.clone());
+            }
+        }
+        if hit_any {
+            referencing_functions.push(function.name.clone());
+        }
+
+        for site in match_coverage_sites(&function.body, role_enum, &variant_res) {
+            partial_coverage_sites.push(PartialCoverageSite {
+                function: function.name.clone(),
+                ..site
+            });
+        }
+    }
+
+    let unchecked_variants = role_enum
+        .variants
+        .iter()
+        .filter(|v| !referenced_variants.contains(*v))
+        .cloned()
+        .collect();
+
+    RoleReport {
+        enum_name: role_enum.name.clone(),
+        variants: role_enum.variants.clone(),
+        referencing_functions,
+        unchecked_variants,
+        partial_coverage_sites,
+    }
+}
+
+/// Find `match` expressions in `body` that dispatch on `role_enum` but
+/// cover fewer than all of its variants via explicit arms.
+fn match_coverage_sites(
+    body: &str,
+    role_enum: &crate::model::EnumDef,
+    variant_res: &[Regex],
+) -> Vec<PartialCoverageSite> {
+    let mut sites = Vec::new();
+    for m in MATCH_RE.find_iter(body) {
+        let Some(block_start) = find_block_start(body, m.end()) else { continue };
+        let Some(block_end) = find_matching_brace(body, block_start) else { continue };
+        let arm_text = &body[block_start..=block_end];
+
+        let covered_variants: Vec<String> = role_enum
+            .variants
+            .iter()
+            .zip(variant_res)
+            .filter(|(_, re)| re.is_match(arm_text))
+            .map(|(v, _)| v.clone())
+            .collect();
+
+        if covered_variants.is_empty() || covered_variants.len() == role_enum.variants.len() {
+            continue;
+        }
+        sites.push(PartialCoverageSite {
+            function: String::new(),
+            has_wildcard: WILDCARD_ARM_RE.is_match(arm_text),
+            covered_variants,
+        });
+    }
+    sites
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::frontend::rust_syn::parse_file;
+    use std::path::PathBuf;
+
+    fn analyze(src: &str) -> RbacReport {
+        let file = parse_file(&PathBuf::from("t.rs"), src);
+        analyze_file(&file)
+    }
+
+    #[test]
+    fn detects_role_enum_and_predicate_method() {
+        let src = r#"
+            enum Role { Admin, Editor, Viewer }
+            impl User {
+                fn is_admin(&self) -> bool { self.role == Role::Admin }
+            }
+        "#;
+        let report = analyze(src);
+        assert_eq!(report.roles[0].enum_name, "Role");
+        assert_eq!(report.predicate_methods[0].name, "is_admin");
+    }
+
+    #[test]
+    fn flags_variant_never_referenced_as_unchecked() {
+        let src = r#"
+            enum Role { Admin, Editor, Viewer }
+            fn describe(role: &Role) -> String {
+                match role {
+                    Role::Admin => "a".to_string(),
+                    _ => "other".to_string(),
+                }
+            }
+        "#;
+        let report = analyze(src);
+        let role = &report.roles[0];
+        assert!(role.unchecked_variants.contains(&"Editor".to_string()));
+        assert!(role.unchecked_variants.contains(&"Viewer".to_string()));
+        assert!(!role.unchecked_variants.contains(&"Admin".to_string()));
+    }
+
+    #[test]
+    fn flat_match_over_every_variant_is_full_coverage() {
+        let src = r#"
+            enum Role { Admin, Editor, Viewer }
+            fn describe(role: &Role) -> String {
+                match role {
+                    Role::Admin => "a".to_string(),
+                    Role::Editor => "e".to_string(),
+                    Role::Viewer => "v".to_string(),
+                }
+            }
+        "#;
+        let report = analyze(src);
+        assert!(report.roles[0].partial_coverage_sites.is_empty());
+    }
+
+    #[test]
+    fn match_missing_a_variant_behind_wildcard_is_partial_coverage() {
+        let src = r#"
+            enum Role { Admin, Editor, Viewer }
+            fn gate(role: &Role) {
+                match role {
+                    Role::Admin => grant(),
+                    Role::Editor => grant(),
+                    _ => deny(),
+                }
+            }
+        "#;
+        let report = analyze(src);
+        let site = &report.roles[0].partial_coverage_sites[0];
+        assert_eq!(site.function, "gate");
+        assert!(site.has_wildcard);
+        assert_eq!(site.covered_variants.len(), 2);
+    }
+}
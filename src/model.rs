@@ -0,0 +1,69 @@
+//! Core data model produced by the language frontends and consumed by
+//! every metric in [`crate::metrics`].
+
+use std::path::PathBuf;
+
+/// A single source file discovered during a scan, together with the
+/// constructs extracted from it.
+#[derive(Debug, Clone)]
+pub struct SourceFile {
+    pub path: PathBuf,
+    pub source: String,
+    pub functions: Vec<Function>,
+    pub enums: Vec<EnumDef>,
+    pub impls: Vec<ImplBlock>,
+}
+
+impl SourceFile {
+    /// All functions in the file, including methods defined inside `impl`
+    /// blocks.
+    pub fn all_functions(&self) -> Vec<&Function> {
+        self.functions
+            .iter()
+            .chain(self.impls.iter().flat_map(|i| i.methods.iter()))
+            .collect()
+    }
+}
+
+/// A free function or method, with its signature and the text of its
+/// body (the contents between, but not including, the outer `{` `}`).
+#[derive(Debug, Clone)]
+pub struct Function {
+    pub name: String,
+    pub is_async: bool,
+    /// Names of the function's own generic type parameters, e.g. `["F"]`
+    /// for `fn transform_users<F>(..)`.
+    pub generics: Vec<String>,
+    pub start_line: usize,
+    pub end_line: usize,
+    pub body: String,
+}
+
+/// An `enum` definition and its variant names, in source order.
+#[derive(Debug, Clone)]
+pub struct EnumDef {
+    pub name: String,
+    pub variants: Vec<String>,
+    pub start_line: usize,
+}
+
+/// A contiguous range of 1-based source lines, used by metrics that need
+/// to point back at more than a single line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LineSpan {
+    pub start: usize,
+    pub end: usize,
+}
+
+/// An `impl` block, optionally implementing a trait, with the methods
+/// defined inside it.
+#[derive(Debug, Clone)]
+pub struct ImplBlock {
+    pub trait_name: Option<String>,
+    pub type_name: String,
+    /// Names of the impl block's own generic type parameters, e.g.
+    /// `["T"]` for `impl<T: Clone> Cache<T>`.
+    pub generics: Vec<String>,
+    pub methods: Vec<Function>,
+    pub start_line: usize,
+}
@@ -0,0 +1,81 @@
+//! Plain-text rendering of metric results for the CLI.
+
+use std::path::Path;
+
+use crate::metrics::complexity::{self, FileComplexity};
+use crate::metrics::concurrency::{ConcurrencyProfile, SharedStateKind, WarningKind};
+use crate::metrics::rbac::RbacReport;
+
+/// Print a per-function complexity table for one file, followed by the
+/// file's aggregate score, flagging any function over `threshold`.
+pub fn print_complexity_report(path: &Path, report: &FileComplexity, threshold: u32) {
+    println!("{}", path.display());
+    for f in &report.functions {
+        let flag = if f.score > threshold { " [!]" } else { "" };
+        println!(
+            "  {:<24} line {:<5} score {:<4}{}",
+            f.name, f.start_line, f.score, flag
+        );
+    }
+    println!("  total: {}", report.total_score);
+
+    let flagged = complexity::over_threshold(report, threshold);
+    if !flagged.is_empty() {
+        println!("  over threshold ({}):", threshold);
+        for f in flagged {
+            println!("    {} (score {})", f.name, f.score);
+        }
+    }
+}
+
+/// Print a concurrency profile for one file: shared-state handles,
+/// spawn/batch fan-out, and any lint-style warnings.
+pub fn print_concurrency_report(path: &Path, profile: &ConcurrencyProfile) {
+    println!("{}", path.display());
+    for handle in &profile.shared_state {
+        let kind = match handle.kind {
+            SharedStateKind::ArcMutex => "Arc<Mutex<_>>",
+            SharedStateKind::ArcRwLock => "Arc<RwLock<_>>",
+        };
+        println!("  line {:<5} shared state: {}", handle.line, kind);
+    }
+    println!(
+        "  thread spawns: {}, join_all batches: {}",
+        profile.thread_spawns, profile.join_all_batches
+    );
+    for warning in &profile.warnings {
+        let kind = match warning.kind {
+            WarningKind::LockInLoop => "lock-in-loop",
+            WarningKind::LockUnwrap => "lock-unwrap",
+            WarningKind::JoinUnwrap => "join-unwrap",
+        };
+        println!(
+            "  [{}] lines {}-{}: {}",
+            kind, warning.span.start, warning.span.end, warning.message
+        );
+    }
+}
+
+/// Print an RBAC report for one file: each role enum with its coverage,
+/// and any predicate methods found.
+pub fn print_rbac_report(path: &Path, report: &RbacReport) {
+    println!("{}", path.display());
+    for role in &report.roles {
+        println!("  role {} {:?}", role.enum_name, role.variants);
+        println!("    checked by: {}", role.referencing_functions.join(", "));
+        if !role.unchecked_variants.is_empty() {
+            println!("    never checked: {}", role.unchecked_variants.join(", "));
+        }
+        for site in &role.partial_coverage_sites {
+            println!(
+                "    [partial coverage] {} handles {:?}{}",
+                site.function,
+                site.covered_variants,
+                if site.has_wildcard { " (rest via wildcard)" } else { " (rest unhandled)" }
+            );
+        }
+    }
+    for method in &report.predicate_methods {
+        println!("  predicate method {} (line {})", method.name, method.start_line);
+    }
+}
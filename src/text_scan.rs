@@ -0,0 +1,155 @@
+//! Low-level text scanning helpers shared by the regex-based metrics.
+//!
+//! These are intentionally simple character scanners rather than a real
+//! lexer: they skip over string/char literals and comments so that brace
+//! matching doesn't get confused by a `"{"` inside a string, but they do
+//! not otherwise understand Rust syntax.
+
+/// Find the index of the `{` that opens the first top-level brace block
+/// starting the scan at `from`, skipping over parentheses/brackets,
+/// string/char literals and comments along the way.
+pub fn find_block_start(source: &str, from: usize) -> Option<usize> {
+    let bytes = source.as_bytes();
+    let mut depth = 0usize;
+    let mut i = from;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'{' if depth == 0 => return Some(i),
+            b'(' | b'[' => {
+                depth += 1;
+                i += 1;
+            }
+            b')' | b']' => {
+                depth = depth.saturating_sub(1);
+                i += 1;
+            }
+            b'"' => i = skip_string(source, i),
+            b'\'' => i = skip_char_literal(source, i),
+            b'/' if bytes.get(i + 1) == Some(&b'/') => i = skip_line_comment(source, i),
+            b'/' if bytes.get(i + 1) == Some(&b'*') => i = skip_block_comment(source, i),
+            _ => i += 1,
+        }
+    }
+    None
+}
+
+/// Given the index of an opening `{`, return the index of its matching `}`.
+pub fn find_matching_brace(source: &str, open: usize) -> Option<usize> {
+    let bytes = source.as_bytes();
+    debug_assert_eq!(bytes.get(open), Some(&b'{'));
+    let mut depth = 0i32;
+    let mut i = open;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'{' => {
+                depth += 1;
+                i += 1;
+            }
+            b'}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(i);
+                }
+                i += 1;
+            }
+            b'"' => i = skip_string(source, i),
+            b'\'' => i = skip_char_literal(source, i),
+            b'/' if bytes.get(i + 1) == Some(&b'/') => i = skip_line_comment(source, i),
+            b'/' if bytes.get(i + 1) == Some(&b'*') => i = skip_block_comment(source, i),
+            _ => i += 1,
+        }
+    }
+    None
+}
+
+/// Count the 1-based line number of byte offset `idx` within `source`.
+pub fn line_of(source: &str, idx: usize) -> usize {
+    source.as_bytes()[..idx.min(source.len())]
+        .iter()
+        .filter(|&&b| b == b'\n')
+        .count()
+        + 1
+}
+
+fn skip_string(source: &str, quote_idx: usize) -> usize {
+    let bytes = source.as_bytes();
+    let mut i = quote_idx + 1;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' => i += 2,
+            b'"' => return i + 1,
+            _ => i += 1,
+        }
+    }
+    i
+}
+
+fn skip_char_literal(source: &str, quote_idx: usize) -> usize {
+    // Could be a char literal ('a', '\n') or a lifetime ('a). Either way,
+    // advancing past a bounded run of non-quote bytes is safe: a lifetime
+    // has no closing quote, so the escaped-scan below simply falls through
+    // without consuming anything it shouldn't.
+    let bytes = source.as_bytes();
+    let mut i = quote_idx + 1;
+    let mut steps = 0;
+    while i < bytes.len() && steps < 4 {
+        match bytes[i] {
+            b'\\' => {
+                let mut j = i + 2;
+                while j < bytes.len() && bytes[j] != b'\'' {
+                    j += 1;
+                }
+                return (j + 1).min(bytes.len());
+            }
+            b'\'' => return i + 1,
+            _ => {
+                i += 1;
+                steps += 1;
+            }
+        }
+    }
+    quote_idx + 1
+}
+
+fn skip_line_comment(source: &str, start: usize) -> usize {
+    source[start..]
+        .find('\n')
+        .map(|off| start + off)
+        .unwrap_or(source.len())
+}
+
+fn skip_block_comment(source: &str, start: usize) -> usize {
+    source[start + 2..]
+        .find("*/")
+        .map(|off| start + 2 + off + 2)
+        .unwrap_or(source.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_matching_brace_across_nesting() {
+        let src = "fn f() { if x { 1 } else { 2 } }";
+        let open = find_block_start(src, 0).unwrap();
+        let close = find_matching_brace(src, open).unwrap();
+        assert_eq!(&src[open..=close], "{ if x { 1 } else { 2 } }");
+    }
+
+    #[test]
+    fn ignores_braces_inside_strings_and_comments() {
+        let src = "fn f() { let s = \"{not a brace\"; /* { */ 1 }";
+        let open = find_block_start(src, 0).unwrap();
+        let close = find_matching_brace(src, open).unwrap();
+        assert_eq!(close, src.len() - 1);
+    }
+
+    #[test]
+    fn line_of_counts_newlines() {
+        let src = "a\nb\nc";
+        assert_eq!(line_of(src, 0), 1);
+        assert_eq!(line_of(src, 2), 2);
+        assert_eq!(line_of(src, 4), 3);
+    }
+}
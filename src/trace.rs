@@ -0,0 +1,189 @@
+//! Opt-in hierarchical tracing for the analysis pipeline.
+//!
+//! Unlike flat log lines, spans opened while another span is active nest
+//! underneath it, so the result is a tree that mirrors the call
+//! structure of the pipeline (e.g. a file's parse span containing one
+//! child span per `impl` block it found). When disabled, [`Tracer`] does
+//! no bookkeeping at all.
+
+use std::cell::RefCell;
+use std::time::{Duration, Instant};
+
+/// One node of a completed span tree: a named unit of work, how many
+/// items it processed, how long it took, and any spans opened while it
+/// was active.
+#[derive(Debug, Clone)]
+pub struct SpanNode {
+    pub name: String,
+    pub item_count: usize,
+    pub duration: Duration,
+    pub children: Vec<SpanNode>,
+}
+
+struct OpenSpan {
+    name: String,
+    start: Instant,
+    item_count: usize,
+    children: Vec<SpanNode>,
+}
+
+/// Records nested spans as they open and close, building a forest of
+/// [`SpanNode`]s. Cheap to construct when disabled: `span()` and
+/// `record_items()` become no-ops.
+#[derive(Default)]
+pub struct Tracer {
+    enabled: bool,
+    stack: RefCell<Vec<OpenSpan>>,
+    roots: RefCell<Vec<SpanNode>>,
+}
+
+impl Tracer {
+    pub fn new(enabled: bool) -> Self {
+        Tracer {
+            enabled,
+            stack: RefCell::new(Vec::new()),
+            roots: RefCell::new(Vec::new()),
+        }
+    }
+
+    pub fn disabled() -> Self {
+        Self::new(false)
+    }
+
+    /// Open a span named `name`; it closes when the returned guard drops.
+    pub fn span(&self, name: &str) -> SpanGuard<'_> {
+        if self.enabled {
+            self.stack.borrow_mut().push(OpenSpan {
+                name: name.to_string(),
+                start: Instant::now(),
+                item_count: 0,
+                children: Vec::new(),
+            });
+        }
+        SpanGuard {
+            tracer: self,
+            active: self.enabled,
+        }
+    }
+
+    /// Add to the item count of the currently open span, if any.
+    pub fn record_items(&self, count: usize) {
+        if let Some(top) = self.stack.borrow_mut().last_mut() {
+            top.item_count += count;
+        }
+    }
+
+    fn close_top(&self) {
+        let closed = {
+            let mut stack = self.stack.borrow_mut();
+            stack.pop()
+        };
+        let Some(open) = closed else { return };
+        let node = SpanNode {
+            name: open.name,
+            item_count: open.item_count,
+            duration: open.start.elapsed(),
+            children: open.children,
+        };
+        let mut stack = self.stack.borrow_mut();
+        if let Some(parent) = stack.last_mut() {
+            parent.children.push(node);
+        } else {
+            drop(stack);
+            self.roots.borrow_mut().push(node);
+        }
+    }
+
+    /// Consume the tracer, returning the top-level spans recorded. Empty
+    /// when the tracer was disabled.
+    pub fn into_roots(self) -> Vec<SpanNode> {
+        self.roots.into_inner()
+    }
+}
+
+/// RAII handle for an open span: closes it on drop.
+pub struct SpanGuard<'a> {
+    tracer: &'a Tracer,
+    active: bool,
+}
+
+impl Drop for SpanGuard<'_> {
+    fn drop(&mut self) {
+        if self.active {
+            self.tracer.close_top();
+        }
+    }
+}
+
+/// Render a span forest as an indented tree, one line per node:
+/// `name -> N items, D ms`.
+pub fn render(roots: &[SpanNode]) -> String {
+    let mut out = String::new();
+    for root in roots {
+        render_node(root, 0, &mut out);
+    }
+    out
+}
+
+fn render_node(node: &SpanNode, depth: usize, out: &mut String) {
+    let indent = "  ".repeat(depth);
+    out.push_str(&format!(
+        "{indent}{} -> {} items, {:.2}ms\n",
+        node.name,
+        node.item_count,
+        node.duration.as_secs_f64() * 1000.0
+    ));
+    for child in &node.children {
+        render_node(child, depth + 1, out);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn disabled_tracer_records_nothing() {
+        let tracer = Tracer::disabled();
+        {
+            let _span = tracer.span("phase");
+            tracer.record_items(5);
+        }
+        assert!(tracer.into_roots().is_empty());
+    }
+
+    #[test]
+    fn enabled_tracer_nests_child_spans_under_parent() {
+        let tracer = Tracer::new(true);
+        {
+            let _outer = tracer.span("parse");
+            tracer.record_items(1);
+            {
+                let _inner = tracer.span("impl UserRepository");
+                tracer.record_items(4);
+            }
+        }
+        let roots = tracer.into_roots();
+        assert_eq!(roots.len(), 1);
+        assert_eq!(roots[0].name, "parse");
+        assert_eq!(roots[0].item_count, 1);
+        assert_eq!(roots[0].children.len(), 1);
+        assert_eq!(roots[0].children[0].name, "impl UserRepository");
+        assert_eq!(roots[0].children[0].item_count, 4);
+    }
+
+    #[test]
+    fn sibling_spans_do_not_nest() {
+        let tracer = Tracer::new(true);
+        {
+            let _a = tracer.span("a");
+        }
+        {
+            let _b = tracer.span("b");
+        }
+        let roots = tracer.into_roots();
+        assert_eq!(roots.len(), 2);
+        assert!(roots[0].children.is_empty());
+        assert!(roots[1].children.is_empty());
+    }
+}